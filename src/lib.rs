@@ -20,10 +20,13 @@
 //! ```
 //! See more examples in the [examples directory](https://github.com/sammhicks/nostd_async/tree/master/examples)
 
+mod cell;
+mod interrupt;
 mod linked_list;
 mod mutex;
 mod non_null;
 pub mod sync;
 mod task;
+pub mod time;
 
-pub use task::{JoinHandle, Runtime, Task};
+pub use task::{join_all, JoinError, JoinHandle, Runtime, Task};
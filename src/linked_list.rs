@@ -1,3 +1,5 @@
+use core::marker::PhantomData;
+
 use bare_metal::CriticalSection;
 
 use crate::{mutex::Mutex, non_null::NonNull};
@@ -51,6 +53,10 @@ impl<T> Clone for LinkedListCore<T> {
 
 impl<T> Copy for LinkedListCore<T> {}
 
+/// An intrusive linked list of `T`.
+///
+/// A node joins a list by implementing [`Link`], which points back at its embedded
+/// [`LinkedListLinks`] field and at the list itself.
 pub struct LinkedList<T> {
     core: Mutex<Option<LinkedListCore<T>>>,
 }
@@ -66,7 +72,7 @@ impl<T> LinkedList<T> {
     }
 }
 
-impl<T: LinkedListItem> LinkedList<T> {
+impl<T: Link> LinkedList<T> {
     pub fn pop_first(&self, cs: &CriticalSection) -> Option<&T> {
         self.core.get(cs).map(|core| {
             let first = unsafe { core.first.as_ref() };
@@ -74,6 +80,27 @@ impl<T: LinkedListItem> LinkedList<T> {
             first
         })
     }
+
+    /// A cursor positioned at the first node in the list, if any.
+    pub fn cursor_front(&self, cs: &CriticalSection) -> Cursor<'_, T> {
+        Cursor {
+            current: self.core.get(cs).map(|core| core.first),
+            _list: PhantomData,
+        }
+    }
+
+    /// Calls `f` with every node currently in the list, front to back.
+    pub fn for_each<F>(&self, cs: &CriticalSection, mut f: F)
+    where
+        F: FnMut(&T),
+    {
+        let mut cursor = self.cursor_front(cs);
+
+        while let Some(node) = cursor.current() {
+            f(node);
+            cursor.move_next(cs);
+        }
+    }
 }
 
 impl<T> Default for LinkedList<T> {
@@ -84,7 +111,47 @@ impl<T> Default for LinkedList<T> {
     }
 }
 
-pub trait LinkedListItem: Sized {
+/// A cursor over a [`LinkedList`], allowing traversal in either direction and removal of a node
+/// found mid-list.
+pub struct Cursor<'a, T> {
+    current: Option<NonNull<T>>,
+    _list: PhantomData<&'a LinkedList<T>>,
+}
+
+impl<'a, T: Link> Cursor<'a, T> {
+    /// The node the cursor is currently positioned at, if any.
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|node| unsafe { node.as_ref() })
+    }
+
+    /// Moves the cursor to the next node in the list.
+    pub fn move_next(&mut self, cs: &CriticalSection) {
+        self.current = self
+            .current
+            .and_then(|node| unsafe { node.as_ref() }.links().next.get(cs));
+    }
+
+    /// Moves the cursor to the previous node in the list.
+    pub fn move_prev(&mut self, cs: &CriticalSection) {
+        self.current = self
+            .current
+            .and_then(|node| unsafe { node.as_ref() }.links().previous.get(cs));
+    }
+
+    /// Removes the current node from the list, leaving the cursor positioned at the node which
+    /// followed it.
+    pub fn remove_current(&mut self, cs: &CriticalSection) {
+        if let Some(node) = self.current {
+            let node = unsafe { node.as_ref() };
+            let next = node.links().next.get(cs);
+            node.remove(cs);
+            self.current = next;
+        }
+    }
+}
+
+/// Lets a node belong to a particular intrusive [`LinkedList`].
+pub trait Link: Sized {
     fn links(&self) -> &LinkedListLinks<Self>;
 
     fn list(&self) -> &LinkedList<Self>;
@@ -205,7 +272,7 @@ pub trait LinkedListItem: Sized {
     }
 }
 
-trait LinkedListItemUtil: LinkedListItem {
+trait LinkUtil: Link {
     fn set_previous(&self, cs: &CriticalSection, previous: Option<NonNull<Self>>) {
         self.links().previous.set(cs, previous);
     }
@@ -215,7 +282,7 @@ trait LinkedListItemUtil: LinkedListItem {
     }
 }
 
-impl<T: LinkedListItem> LinkedListItemUtil for T {}
+impl<T: Link> LinkUtil for T {}
 
 #[cfg(test)]
 mod tests {
@@ -306,7 +373,7 @@ mod tests {
         }
     }
 
-    impl<'a> LinkedListItem for Node<'a> {
+    impl<'a> Link for Node<'a> {
         fn links(&self) -> &LinkedListLinks<Self> {
             &self.links
         }
@@ -470,4 +537,95 @@ mod tests {
     fn triple_list_is_valid_210() {
         run_triple_test([2, 1, 0]);
     }
+
+    #[test]
+    fn cursor_visits_every_node_front_to_back() {
+        interrupt::free(|cs| {
+            let list = TestLinkedList::default();
+
+            let nodes = [Node::new(&list), Node::new(&list), Node::new(&list)];
+
+            for node in nodes.iter() {
+                node.insert_back(cs);
+            }
+
+            let mut cursor = list.list.cursor_front(cs);
+
+            for node in nodes.iter() {
+                assert!(core::ptr::eq(cursor.current().unwrap(), node));
+                cursor.move_next(cs);
+            }
+
+            assert!(cursor.current().is_none());
+        });
+    }
+
+    #[test]
+    fn cursor_visits_every_node_back_to_front() {
+        interrupt::free(|cs| {
+            let list = TestLinkedList::default();
+
+            let nodes = [Node::new(&list), Node::new(&list), Node::new(&list)];
+
+            for node in nodes.iter() {
+                node.insert_back(cs);
+            }
+
+            let mut cursor = list.list.cursor_front(cs);
+            cursor.move_next(cs);
+            cursor.move_next(cs);
+
+            for node in nodes.iter().rev() {
+                assert!(core::ptr::eq(cursor.current().unwrap(), node));
+                cursor.move_prev(cs);
+            }
+
+            assert!(cursor.current().is_none());
+        });
+    }
+
+    #[test]
+    fn for_each_visits_every_node() {
+        interrupt::free(|cs| {
+            let list = TestLinkedList::default();
+
+            let nodes = [Node::new(&list), Node::new(&list), Node::new(&list)];
+
+            for node in nodes.iter() {
+                node.insert_back(cs);
+            }
+
+            let mut visited = 0;
+
+            list.list.for_each(cs, |_| visited += 1);
+
+            assert_eq!(visited, nodes.len());
+        });
+    }
+
+    #[test]
+    fn remove_current_splices_out_middle_node_and_advances_cursor() {
+        interrupt::free(|cs| {
+            let list = TestLinkedList::default();
+
+            let nodes = [Node::new(&list), Node::new(&list), Node::new(&list)];
+
+            for node in nodes.iter() {
+                node.insert_back(cs);
+            }
+
+            let mut cursor = list.list.cursor_front(cs);
+            cursor.move_next(cs);
+
+            assert!(core::ptr::eq(cursor.current().unwrap(), &nodes[1]));
+
+            cursor.remove_current(cs);
+
+            list.assert_is_valid();
+            assert!(!list.contains(&nodes[1], cs));
+            assert!(!nodes[1].is_in_queue(cs));
+
+            assert!(core::ptr::eq(cursor.current().unwrap(), &nodes[2]));
+        });
+    }
 }
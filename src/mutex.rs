@@ -15,6 +15,14 @@ impl<T> Mutex<T> {
     pub fn set(&self, cs: &CriticalSection, value: T) {
         unsafe { *self.0.borrow(cs).get() = value };
     }
+
+    /// Calls `f` with a mutable reference to the contained value.
+    pub fn with<F, R>(&self, cs: &CriticalSection, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        f(unsafe { &mut *self.0.borrow(cs).get() })
+    }
 }
 
 impl<T: Copy> Mutex<T> {
@@ -104,6 +112,21 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_with() {
+        interrupt_free(|cs| {
+            let c = Mutex::new(12);
+
+            let doubled = c.with(cs, |value| {
+                *value *= 2;
+                *value
+            });
+
+            assert_eq!(doubled, 24);
+            assert_eq!(c.get(cs), 24);
+        });
+    }
+
     #[test]
     fn test_has_some() {
         interrupt_free(|cs| {
@@ -0,0 +1,341 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::{
+    linked_list::{Link, LinkedList, LinkedListLinks},
+    mutex::Mutex,
+};
+
+struct Ring<T, const N: usize> {
+    items: [Option<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> Ring<T, N> {
+    fn new() -> Self {
+        Self {
+            items: core::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    fn push(&mut self, value: T) {
+        let index = (self.head + self.len) % N;
+        self.items[index] = Some(value);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let value = self.items[self.head].take();
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+        value
+    }
+}
+
+/// A capacity-bounded buffered channel, decoupling the timing of producer and consumer.
+///
+/// Unlike [`mpmc::Buffer`](super::mpmc::Buffer), up to `N` values can be in flight without a
+/// matching receiver being ready to receive them.
+pub struct BoundedBuffer<'b, T, const N: usize> {
+    senders: LinkedList<Send<'b, T, N>>,
+    receivers: LinkedList<Receive<'b, T, N>>,
+    queue: Mutex<Ring<T, N>>,
+}
+
+impl<'b, T, const N: usize> BoundedBuffer<'b, T, N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sender(&'b self) -> Sender<'b, T, N> {
+        Sender { buffer: self }
+    }
+
+    pub fn receiver(&'b self) -> Receiver<'b, T, N> {
+        Receiver { buffer: self }
+    }
+}
+
+impl<'b, T, const N: usize> Default for BoundedBuffer<'b, T, N> {
+    fn default() -> Self {
+        Self {
+            senders: LinkedList::default(),
+            receivers: LinkedList::default(),
+            queue: Mutex::new(Ring::new()),
+        }
+    }
+}
+
+pub struct Sender<'b, T, const N: usize> {
+    buffer: &'b BoundedBuffer<'b, T, N>,
+}
+
+impl<'b, T, const N: usize> Sender<'b, T, N> {
+    #[must_use = "Send does nothing until it is polled or awaited"]
+    pub fn send(&self, value: T) -> Send<'b, T, N> {
+        Send {
+            buffer: self.buffer,
+            value: Mutex::new(Some(value)),
+            waker: Mutex::new(None),
+            links: LinkedListLinks::default(),
+        }
+    }
+}
+
+pub struct Send<'b, T, const N: usize> {
+    buffer: &'b BoundedBuffer<'b, T, N>,
+    value: Mutex<Option<T>>,
+    waker: Mutex<Option<Waker>>,
+    links: LinkedListLinks<Self>,
+}
+
+impl<'b, T, const N: usize> Link for Send<'b, T, N> {
+    fn links(&self) -> &LinkedListLinks<Self> {
+        &self.links
+    }
+
+    fn list(&self) -> &LinkedList<Self> {
+        &self.buffer.senders
+    }
+}
+
+impl<'b, T, const N: usize> Future for Send<'b, T, N> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        critical_section::with(|cs| {
+            let this = unsafe { self.get_unchecked_mut() };
+
+            if this.value.has_none(cs) {
+                this.remove(cs);
+                return Poll::Ready(());
+            }
+
+            // A slot being free is not enough on its own: if other `Send`s are already parked
+            // ahead of this one, they must get the slot first. Only the sender at the front of
+            // the queue (or one that was never queued at all) is allowed to take the fast path,
+            // so a freshly-polled `Send` can't jump ahead of one that has been waiting longer.
+            let is_front_of_queue = this
+                .buffer
+                .senders
+                .with_first(cs, |first| core::ptr::eq(first, this))
+                .unwrap_or(true);
+
+            let has_space = this.buffer.queue.with(cs, |queue| !queue.is_full());
+
+            if has_space && is_front_of_queue {
+                let value = this.value.take(cs).expect("Send has value");
+                this.buffer.queue.with(cs, |queue| queue.push(value));
+                this.remove(cs);
+
+                this.buffer.receivers.with_first(cs, |receiver| {
+                    if let Some(waker) = receiver.waker.take(cs) {
+                        waker.wake();
+                    }
+                });
+
+                Poll::Ready(())
+            } else {
+                this.insert_back(cs);
+                this.waker.set(cs, Some(cx.waker().clone()));
+                Poll::Pending
+            }
+        })
+    }
+}
+
+impl<'b, T, const N: usize> Drop for Send<'b, T, N> {
+    fn drop(&mut self) {
+        critical_section::with(|cs| self.remove(cs));
+    }
+}
+
+pub struct Receiver<'b, T, const N: usize> {
+    buffer: &'b BoundedBuffer<'b, T, N>,
+}
+
+impl<'b, T, const N: usize> Receiver<'b, T, N> {
+    #[must_use = "Receive does nothing until it is polled or awaited"]
+    pub fn receive(&self) -> Receive<'b, T, N> {
+        Receive {
+            buffer: self.buffer,
+            waker: Mutex::new(None),
+            links: LinkedListLinks::default(),
+        }
+    }
+}
+
+pub struct Receive<'b, T, const N: usize> {
+    buffer: &'b BoundedBuffer<'b, T, N>,
+    waker: Mutex<Option<Waker>>,
+    links: LinkedListLinks<Self>,
+}
+
+impl<'b, T, const N: usize> Link for Receive<'b, T, N> {
+    fn links(&self) -> &LinkedListLinks<Self> {
+        &self.links
+    }
+
+    fn list(&self) -> &LinkedList<Self> {
+        &self.buffer.receivers
+    }
+}
+
+impl<'b, T, const N: usize> Future for Receive<'b, T, N> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        critical_section::with(|cs| {
+            let this = unsafe { self.get_unchecked_mut() };
+
+            let value = this.buffer.queue.with(cs, |queue| queue.pop());
+
+            if let Some(value) = value {
+                this.remove(cs);
+
+                this.buffer.senders.with_first(cs, |sender| {
+                    if let Some(waker) = sender.waker.take(cs) {
+                        waker.wake();
+                    }
+                });
+
+                Poll::Ready(value)
+            } else {
+                this.insert_back(cs);
+                this.waker.set(cs, Some(cx.waker().clone()));
+                Poll::Pending
+            }
+        })
+    }
+}
+
+impl<'b, T, const N: usize> Drop for Receive<'b, T, N> {
+    fn drop(&mut self) {
+        critical_section::with(|cs| self.remove(cs));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        future::Future,
+        task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    };
+
+    use super::BoundedBuffer;
+    use crate::{Runtime, Task};
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            raw()
+        }
+        fn no_op(_: *const ()) {}
+        fn raw() -> RawWaker {
+            RawWaker::new(core::ptr::null(), &VTABLE)
+        }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        unsafe { Waker::from_raw(raw()) }
+    }
+
+    #[test]
+    fn send_then_receive_preserves_order_up_to_capacity() {
+        let buffer: BoundedBuffer<i32, 2> = BoundedBuffer::new();
+        let sender = buffer.sender();
+        let receiver = buffer.receiver();
+
+        let runtime = Runtime::new();
+
+        runtime.block_on(async {
+            sender.send(1).await;
+            sender.send(2).await;
+        });
+
+        assert_eq!(runtime.block_on(receiver.receive()), 1);
+        assert_eq!(runtime.block_on(receiver.receive()), 2);
+    }
+
+    #[test]
+    fn send_waits_for_space_once_the_buffer_is_full() {
+        let buffer: BoundedBuffer<i32, 1> = BoundedBuffer::new();
+        let sender = buffer.sender();
+        let receiver = buffer.receiver();
+
+        let runtime = Runtime::new();
+
+        static SECOND_SENT: core::sync::atomic::AtomicBool =
+            core::sync::atomic::AtomicBool::new(false);
+
+        let producer = core::pin::pin!(Task::new(async {
+            sender.send(1).await;
+            sender.send(2).await;
+            SECOND_SENT.store(true, core::sync::atomic::Ordering::Relaxed);
+        }));
+
+        let producer_handle = runtime.spawn(producer);
+
+        runtime.run_once();
+        assert!(!SECOND_SENT.load(core::sync::atomic::Ordering::Relaxed));
+
+        assert_eq!(runtime.block_on(receiver.receive()), 1);
+
+        producer_handle.join();
+        assert!(SECOND_SENT.load(core::sync::atomic::Ordering::Relaxed));
+
+        assert_eq!(runtime.block_on(receiver.receive()), 2);
+    }
+
+    #[test]
+    fn a_freed_slot_goes_to_the_longest_waiting_sender_not_a_newcomer() {
+        // Regression test: A fills the buffer and blocks on a 2nd send; once a receive frees a
+        // slot, a brand-new `Send` (never polled before) must not steal that slot ahead of A,
+        // even though it sees the same `!queue.is_full()` fast path A is waiting on.
+        let buffer: BoundedBuffer<i32, 1> = BoundedBuffer::new();
+        let sender = buffer.sender();
+        let receiver = buffer.receiver();
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut a_send_1 = core::pin::pin!(sender.send(1));
+        assert_eq!(a_send_1.as_mut().poll(&mut cx), Poll::Ready(()));
+
+        let mut a_send_2 = core::pin::pin!(sender.send(2));
+        assert_eq!(a_send_2.as_mut().poll(&mut cx), Poll::Pending);
+
+        let mut d_receive = core::pin::pin!(receiver.receive());
+        assert_eq!(d_receive.as_mut().poll(&mut cx), Poll::Ready(1));
+
+        // X is polled for the very first time right after the slot frees up, before A gets a
+        // chance to re-poll - it must still queue behind A rather than jump ahead.
+        let mut x_send = core::pin::pin!(sender.send(999));
+        assert_eq!(x_send.as_mut().poll(&mut cx), Poll::Pending);
+
+        assert_eq!(a_send_2.as_mut().poll(&mut cx), Poll::Ready(()));
+
+        assert_eq!(
+            core::pin::pin!(receiver.receive()).as_mut().poll(&mut cx),
+            Poll::Ready(2)
+        );
+
+        assert_eq!(x_send.as_mut().poll(&mut cx), Poll::Ready(()));
+
+        assert_eq!(
+            core::pin::pin!(receiver.receive()).as_mut().poll(&mut cx),
+            Poll::Ready(999)
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod bounded;
+pub mod mpmc;
+pub mod notify;
+pub mod semaphore;
@@ -5,7 +5,7 @@ use core::{
 };
 
 use crate::{
-    linked_list::{LinkedList, LinkedListItem, LinkedListLinks},
+    linked_list::{Link, LinkedList, LinkedListLinks},
     mutex::Mutex,
 };
 
@@ -63,7 +63,7 @@ pub struct Send<'b, T> {
     links: LinkedListLinks<Self>,
 }
 
-impl<'b, T> LinkedListItem for Send<'b, T> {
+impl<'b, T> Link for Send<'b, T> {
     fn links(&self) -> &LinkedListLinks<Self> {
         &self.links
     }
@@ -126,7 +126,7 @@ pub struct Receive<'b, T> {
     links: LinkedListLinks<Self>,
 }
 
-impl<'b, T> LinkedListItem for Receive<'b, T> {
+impl<'b, T> Link for Receive<'b, T> {
     fn links(&self) -> &LinkedListLinks<Self> {
         &self.links
     }
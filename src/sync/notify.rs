@@ -0,0 +1,215 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use crate::{
+    cell::Cell,
+    linked_list::{Link, LinkedList, LinkedListLinks},
+    mutex::Mutex,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Init,
+    Waiting,
+    Notified,
+}
+
+/// A synchronization primitive which can be used to wake a task waiting for some event to occur.
+///
+/// A permit raised by [`notify_one`](Notify::notify_one) while no task is waiting is stored
+/// rather than lost - the next call to [`notified`](Notify::notified) completes immediately.
+pub struct Notify<'b> {
+    waiters: LinkedList<Waiter<'b>>,
+    permit: Mutex<bool>,
+}
+
+impl<'b> Notify<'b> {
+    /// Creates a new `Notify`, with no permit and no waiters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Waits until [`notify_one`](Notify::notify_one) or [`notify_waiters`](Notify::notify_waiters) is called.
+    ///
+    /// If a permit is already available, it is consumed immediately.
+    #[must_use = "Waiter does nothing until it is polled or awaited"]
+    pub fn notified(&'b self) -> Waiter<'b> {
+        Waiter {
+            notify: self,
+            state: Cell::new(State::Init),
+            waker: Cell::new(None),
+            links: LinkedListLinks::default(),
+        }
+    }
+
+    /// Wakes one waiting task.
+    ///
+    /// If no task is currently waiting, a permit is stored so that the next call to
+    /// [`notified`](Notify::notified) completes immediately.
+    pub fn notify_one(&self) {
+        critical_section::with(|cs| {
+            if let Some(waiter) = self.waiters.pop_first(cs) {
+                waiter.state.set(State::Notified);
+                if let Some(waker) = waiter.waker.take() {
+                    waker.wake();
+                }
+            } else {
+                self.permit.set(cs, true);
+            }
+        });
+    }
+
+    /// Wakes all currently waiting tasks.
+    ///
+    /// Unlike [`notify_one`](Notify::notify_one), this never stores a permit for later.
+    pub fn notify_waiters(&self) {
+        critical_section::with(|cs| {
+            while let Some(waiter) = self.waiters.pop_first(cs) {
+                waiter.state.set(State::Notified);
+                if let Some(waker) = waiter.waker.take() {
+                    waker.wake();
+                }
+            }
+        });
+    }
+}
+
+impl<'b> Default for Notify<'b> {
+    fn default() -> Self {
+        Self {
+            waiters: LinkedList::default(),
+            permit: Mutex::new(false),
+        }
+    }
+}
+
+/// The [`Future`] returned by [`Notify::notified`].
+pub struct Waiter<'b> {
+    notify: &'b Notify<'b>,
+    state: Cell<State>,
+    waker: Cell<Option<Waker>>,
+    links: LinkedListLinks<Self>,
+}
+
+impl<'b> Link for Waiter<'b> {
+    fn links(&self) -> &LinkedListLinks<Self> {
+        &self.links
+    }
+
+    fn list(&self) -> &LinkedList<Self> {
+        &self.notify.waiters
+    }
+}
+
+impl<'b> Future for Waiter<'b> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        critical_section::with(|cs| {
+            let this = unsafe { self.get_unchecked_mut() };
+
+            if this.state.get() == State::Notified {
+                return Poll::Ready(());
+            }
+
+            if this.state.get() == State::Init && this.notify.permit.take(cs) {
+                return Poll::Ready(());
+            }
+
+            this.waker.set(Some(cx.waker().clone()));
+            this.insert_back(cs);
+            this.state.set(State::Waiting);
+            Poll::Pending
+        })
+    }
+}
+
+impl<'b> Drop for Waiter<'b> {
+    fn drop(&mut self) {
+        critical_section::with(|cs| self.remove(cs));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Notify;
+    use crate::{Runtime, Task};
+
+    #[test]
+    fn notify_one_stores_a_permit_for_a_later_waiter() {
+        let notify = Notify::new();
+
+        // No one is waiting yet, so this permit is stored rather than lost.
+        notify.notify_one();
+
+        let runtime = Runtime::new();
+
+        assert_eq!(
+            runtime.block_on(async {
+                notify.notified().await;
+                42
+            }),
+            42
+        );
+    }
+
+    #[test]
+    fn notify_one_wakes_a_single_parked_waiter() {
+        let runtime = Runtime::new();
+        let notify = Notify::new();
+
+        static WOKEN: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+
+        let task = core::pin::pin!(Task::new(async {
+            notify.notified().await;
+            WOKEN.store(true, core::sync::atomic::Ordering::Relaxed);
+        }));
+
+        let handle = runtime.spawn(task);
+
+        runtime.run_once();
+        assert!(!WOKEN.load(core::sync::atomic::Ordering::Relaxed));
+
+        notify.notify_one();
+        runtime.run_once();
+
+        assert!(WOKEN.load(core::sync::atomic::Ordering::Relaxed));
+
+        drop(handle);
+    }
+
+    #[test]
+    fn notify_waiters_wakes_every_parked_waiter() {
+        let runtime = Runtime::new();
+        let notify = Notify::new();
+
+        static WOKEN_COUNT: core::sync::atomic::AtomicUsize =
+            core::sync::atomic::AtomicUsize::new(0);
+
+        let task_a = core::pin::pin!(Task::new(async {
+            notify.notified().await;
+            WOKEN_COUNT.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        }));
+        let task_b = core::pin::pin!(Task::new(async {
+            notify.notified().await;
+            WOKEN_COUNT.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        }));
+
+        let handle_a = runtime.spawn(task_a);
+        let handle_b = runtime.spawn(task_b);
+
+        runtime.run_batch(2);
+        assert_eq!(WOKEN_COUNT.load(core::sync::atomic::Ordering::Relaxed), 0);
+
+        notify.notify_waiters();
+        runtime.run_batch(2);
+
+        assert_eq!(WOKEN_COUNT.load(core::sync::atomic::Ordering::Relaxed), 2);
+
+        drop(handle_a);
+        drop(handle_b);
+    }
+}
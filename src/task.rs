@@ -6,7 +6,7 @@ use core::{
 };
 
 use crate::{
-    linked_list::{LinkedList, LinkedListItem, LinkedListLinks},
+    linked_list::{Link, LinkedList, LinkedListLinks},
     mutex::Mutex,
     non_null::NonNull,
 };
@@ -31,6 +31,7 @@ static RAW_WAKER_VTABLE: RawWakerVTable =
 struct TaskCore {
     runtime: NonNull<Runtime>,
     task_handle: Mutex<Option<core::ptr::NonNull<dyn Future<Output = ()>>>>,
+    cancelled: Mutex<bool>,
     links: LinkedListLinks<Self>,
 }
 
@@ -57,7 +58,7 @@ impl core::ops::Drop for TaskCore {
     }
 }
 
-impl LinkedListItem for TaskCore {
+impl Link for TaskCore {
     fn links(&self) -> &LinkedListLinks<Self> {
         &self.links
     }
@@ -67,6 +68,13 @@ impl LinkedListItem for TaskCore {
     }
 }
 
+/// The task was cancelled before it completed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinError {
+    /// [`JoinHandle::cancel`] was called before the task completed.
+    Cancelled,
+}
+
 /// A joinable handle for a task.
 pub struct JoinHandle<'a, T> {
     task_core: &'a TaskCore,
@@ -82,12 +90,75 @@ impl<'a, T> JoinHandle<'a, T> {
     ///
     /// Panics if there's a bug in `nostd_async`
     pub fn join(self) -> T {
+        let runtime = unsafe { self.task_core.runtime.as_ref() };
+
         while critical_section::with(|cs| self.task_core.task_handle.has_some(cs)) {
-            unsafe { self.task_core.runtime.as_ref().run_once() };
+            runtime.run_batch(runtime.batch_size());
         }
 
         critical_section::with(|cs| self.result.take(cs).expect("No Result"))
     }
+
+    /// Drive the runtime until the handle's task completes, returning a [`JoinError`] if it was
+    /// [`cancel`](JoinHandle::cancel)led instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's a bug in `nostd_async`
+    pub fn try_join(self) -> Result<T, JoinError> {
+        let runtime = unsafe { self.task_core.runtime.as_ref() };
+
+        while critical_section::with(|cs| {
+            self.task_core.task_handle.has_some(cs) && !self.task_core.cancelled.get(cs)
+        }) {
+            runtime.run_batch(runtime.batch_size());
+        }
+
+        critical_section::with(|cs| {
+            if self.task_core.cancelled.get(cs) {
+                Err(JoinError::Cancelled)
+            } else {
+                Ok(self.result.take(cs).expect("No Result"))
+            }
+        })
+    }
+
+    /// Stop driving the handle's task, removing it from the runtime's queue.
+    ///
+    /// Note that this does not drop the task itself, which remains owned by its pinned [`Task`];
+    /// it merely stops the runtime from ever polling it again. The handle itself is left intact,
+    /// so it can still be passed to [`try_join`](JoinHandle::try_join) afterwards to observe the
+    /// resulting [`JoinError::Cancelled`].
+    pub fn cancel(&self) {
+        critical_section::with(|cs| {
+            self.task_core.remove(cs);
+            self.task_core.task_handle.take(cs);
+            self.task_core.cancelled.set(cs, true);
+        });
+    }
+}
+
+/// Drives a single shared runtime until every handle in `handles` has completed, then returns
+/// each handle's result in the same order.
+///
+/// Unlike calling [`join`](JoinHandle::join) on each handle in turn, this drives every task
+/// concurrently from a single scheduling loop, so one handle's task is not run to completion
+/// before another's gets a chance to run.
+///
+/// # Panics
+///
+/// Panics if `handles` is empty, or if there's a bug in `nostd_async`
+pub fn join_all<const N: usize, T>(handles: [JoinHandle<'_, T>; N]) -> [T; N] {
+    let runtime = unsafe { handles[0].task_core.runtime.as_ref() };
+
+    while handles
+        .iter()
+        .any(|handle| critical_section::with(|cs| handle.task_core.task_handle.has_some(cs)))
+    {
+        runtime.run_batch(runtime.batch_size());
+    }
+
+    handles.map(|handle| critical_section::with(|cs| handle.result.take(cs).expect("No Result")))
 }
 
 struct CapturingFuture<F: Future> {
@@ -131,12 +202,29 @@ where
     }
 }
 
+/// The default batch size of a [`Runtime`]: the `max` that [`JoinHandle::join`],
+/// [`JoinHandle::try_join`] and [`join_all`] pass to [`Runtime::run_batch`].
+pub const DEFAULT_BATCH_SIZE: usize = 32;
+
 /// The asyncronous runtime.
 ///
 /// Note that it is **not threadsafe** and should thus only be run from a single thread.
-#[derive(Default)]
 pub struct Runtime {
     tasks: LinkedList<TaskCore>,
+    batch_size: Mutex<usize>,
+    pub(crate) timers: LinkedList<crate::time::DelayCore>,
+    clock: Mutex<Option<&'static dyn crate::time::Clock>>,
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self {
+            tasks: LinkedList::default(),
+            batch_size: Mutex::new(DEFAULT_BATCH_SIZE),
+            timers: LinkedList::default(),
+            clock: Mutex::new(None),
+        }
+    }
 }
 
 impl Runtime {
@@ -145,6 +233,26 @@ impl Runtime {
         Self::default()
     }
 
+    /// Sets the batch size: the `max` that [`JoinHandle::join`], [`JoinHandle::try_join`] and
+    /// [`join_all`] pass to [`run_batch`](Runtime::run_batch). A direct call to `run_batch` uses
+    /// its own `max` argument instead.
+    pub fn set_batch_size(&self, batch_size: usize) {
+        critical_section::with(|cs| self.batch_size.set(cs, batch_size));
+    }
+
+    /// Registers the [`Clock`](crate::time::Clock) used to evaluate
+    /// [`Delay`](crate::time::Delay)/[`timeout`](crate::time::timeout) deadlines.
+    ///
+    /// The clock must be `'static`; on bare metal this is usually a `static` instance of a type
+    /// wrapping the target's tick counter/RTC.
+    pub fn set_clock(&self, clock: &'static dyn crate::time::Clock) {
+        critical_section::with(|cs| self.clock.set(cs, Some(clock)));
+    }
+
+    pub(crate) fn clock(&self) -> Option<&'static dyn crate::time::Clock> {
+        critical_section::with(|cs| self.clock.get(cs))
+    }
+
     /// Spawn the task.
     /// Note that the task will not be run until a join handle is joined.
     ///
@@ -172,6 +280,7 @@ impl Runtime {
             let task_core = core.get_or_insert(TaskCore {
                 task_handle,
                 runtime: NonNull::new(self),
+                cancelled: Mutex::new(false),
                 links: LinkedListLinks::default(),
             });
 
@@ -184,29 +293,87 @@ impl Runtime {
         }
     }
 
-    unsafe fn run_once(&self) {
-        let first_task = critical_section::with(|cs| {
+    /// Pin `future` on the stack and drive this runtime until it completes, returning its output.
+    ///
+    /// This avoids having to construct and pin a [`Task`] for a single top-level future.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's a bug in `nostd_async`
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let task = core::pin::pin!(Task::new(future));
+
+        self.spawn(task).join()
+    }
+
+    /// Schedules a wake for the next due timer (if any) and idles (`wfe`/`sleep`) until woken,
+    /// called whenever the task queue is found empty.
+    fn idle(&self, cs: &bare_metal::CriticalSection) {
+        if let Some(clock) = self.clock.get(cs) {
+            if let Some(deadline) = self.earliest_timer_deadline(cs) {
+                clock.schedule_wake(deadline);
+            }
+        }
+
+        #[cfg(feature = "avr")]
+        avr_device::asm::sleep();
+        #[cfg(feature = "cortex_m")]
+        cortex_m::asm::wfe();
+    }
+
+    fn pop_front_or_idle(&self) -> Option<&TaskCore> {
+        critical_section::with(|cs| {
+            self.fire_due_timers(cs);
+
             let first_task = self.tasks.pop_first(cs);
 
             if first_task.is_none() {
-                #[cfg(feature = "avr")]
-                avr_device::asm::sleep();
-                #[cfg(feature = "cortex_m")]
-                cortex_m::asm::wfe();
+                self.idle(cs);
             }
 
             first_task
-        });
+        })
+    }
 
-        if let Some(first_task) = first_task {
+    /// Runs a single task from the front of the queue, idling (`wfe`/`sleep`) if the queue is
+    /// empty.
+    pub fn run_once(&self) {
+        if let Some(first_task) = self.pop_front_or_idle() {
             first_task.run_once();
         }
     }
+
+    /// Drains and runs up to `max` tasks from the front of the queue in a single scheduling
+    /// batch, only idling (`wfe`/`sleep`) if the queue is still empty once the batch boundary is
+    /// reached.
+    ///
+    /// Tasks rewoken while their batch is still being drained are appended to the back of the
+    /// queue, deferring them past every task that was already waiting, so one frequently-woken
+    /// task cannot starve the others. Unlike calling [`run_once`](Runtime::run_once) in a loop,
+    /// due timers are only scanned for once per batch rather than once per task, cutting down on
+    /// the per-task cost of re-checking the timer queue.
+    pub fn run_batch(&self, max: usize) {
+        critical_section::with(|cs| self.fire_due_timers(cs));
+
+        for _ in 0..max {
+            match critical_section::with(|cs| self.tasks.pop_first(cs)) {
+                Some(first_task) => first_task.run_once(),
+                None => {
+                    critical_section::with(|cs| self.idle(cs));
+                    return;
+                }
+            }
+        }
+    }
+
+    fn batch_size(&self) -> usize {
+        critical_section::with(|cs| self.batch_size.get(cs))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Runtime, Task};
+    use super::{join_all, JoinError, Runtime, Task};
 
     #[test]
     fn test_never_spawned() {
@@ -232,6 +399,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_block_on() {
+        let runtime = Runtime::new();
+
+        assert_eq!(runtime.block_on(async { 42 }), 42);
+    }
+
+    #[test]
+    fn test_join_after_run_batch_with_batch_size_one() {
+        let runtime = Runtime::new();
+        runtime.set_batch_size(1);
+
+        let task = core::pin::pin!(Task::new(async { 1 }));
+        let handle = runtime.spawn(task);
+
+        runtime.run_batch(1);
+
+        assert_eq!(handle.join(), 1);
+    }
+
+    #[test]
+    fn test_join_all() {
+        let runtime = Runtime::new();
+
+        let t1 = core::pin::pin!(Task::new(async { 1 }));
+        let t2 = core::pin::pin!(Task::new(async { 2 }));
+        let t3 = core::pin::pin!(Task::new(async { 3 }));
+
+        let h1 = runtime.spawn(t1);
+        let h2 = runtime.spawn(t2);
+        let h3 = runtime.spawn(t3);
+
+        assert_eq!(join_all([h1, h2, h3]), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_try_join_completed() {
+        let runtime = Runtime::new();
+
+        let task = core::pin::pin!(Task::new(async { 1 }));
+
+        assert_eq!(runtime.spawn(task).try_join(), Ok(1));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let runtime = Runtime::new();
+
+        let mut polled = false;
+
+        let task = core::pin::pin!(Task::new(async {
+            polled = true;
+        }));
+
+        runtime.spawn(task).cancel();
+
+        assert!(!polled);
+    }
+
+    #[test]
+    fn test_try_join_after_cancel() {
+        let runtime = Runtime::new();
+
+        let task = core::pin::pin!(Task::new(async { 1 }));
+
+        let handle = runtime.spawn(task);
+        handle.cancel();
+
+        assert_eq!(handle.try_join(), Err(JoinError::Cancelled));
+    }
+
     #[test]
     fn test_drop_handle() {
         let runtime = Runtime::new();
@@ -0,0 +1,299 @@
+use core::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+use bare_metal::CriticalSection;
+
+use crate::{
+    cell::Cell,
+    linked_list::{Link, LinkedList, LinkedListLinks},
+    non_null::NonNull,
+    Runtime,
+};
+
+/// A monotonic tick source driving [`Delay`]/[`timeout`].
+///
+/// The unit of `now()` is entirely up to the implementation (milliseconds, SysTick ticks, RTC
+/// counts, ...) - whatever unit is chosen must be used consistently for every `duration` passed
+/// to [`Delay::new`]/[`timeout`].
+pub trait Clock {
+    /// Returns the current time, in this clock's own unit.
+    fn now(&self) -> u64;
+
+    /// Called with the deadline of the next timer to fire, just before the runtime goes idle, so
+    /// an implementation backed by e.g. a Cortex-M SysTick/RTC comparator can program an
+    /// interrupt to wake the core at (or before) that deadline.
+    ///
+    /// The default implementation does nothing, relying on some other interrupt waking the core
+    /// in the meantime.
+    fn schedule_wake(&self, _deadline: u64) {}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Init,
+    Waiting,
+    Fired,
+}
+
+// The node actually embedded in `Runtime::timers`. It is not lifetime-parameterized over the
+// `Runtime` it belongs to - like `TaskCore`, it can't be, since `Runtime` itself owns the list it
+// is threaded into - so it falls back to the same `NonNull` back-pointer `TaskCore` uses. The
+// public `Delay` wrapping it is what actually ties the borrow to a `Runtime`'s lifetime.
+pub(crate) struct DelayCore {
+    runtime: NonNull<Runtime>,
+    deadline: u64,
+    state: Cell<State>,
+    waker: Cell<Option<Waker>>,
+    links: LinkedListLinks<Self>,
+}
+
+impl DelayCore {
+    fn deadline(&self) -> u64 {
+        self.deadline
+    }
+}
+
+impl Link for DelayCore {
+    fn links(&self) -> &LinkedListLinks<Self> {
+        &self.links
+    }
+
+    fn list(&self) -> &LinkedList<Self> {
+        unsafe { &self.runtime.as_ref().timers }
+    }
+}
+
+/// A [`Future`] which completes after approximately a fixed number of a [`Clock`]'s ticks have
+/// passed.
+///
+/// Borrows the [`Runtime`] it was created from for `'r`, so it cannot outlive it.
+pub struct Delay<'r> {
+    core: DelayCore,
+    _runtime: PhantomData<&'r Runtime>,
+}
+
+impl<'r> Delay<'r> {
+    /// Creates a new `Delay`, completing once `runtime`'s registered [`Clock`] reaches `duration`
+    /// ticks from now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no [`Clock`] has been registered with `runtime` via
+    /// [`Runtime::set_clock`](crate::Runtime::set_clock).
+    #[must_use = "Delay does nothing until it is polled or awaited"]
+    pub fn new(runtime: &'r Runtime, duration: u64) -> Self {
+        let now = runtime.clock().expect("No Clock registered").now();
+
+        Self {
+            core: DelayCore {
+                runtime: NonNull::new(runtime),
+                deadline: now.wrapping_add(duration),
+                state: Cell::new(State::Init),
+                waker: Cell::new(None),
+                links: LinkedListLinks::default(),
+            },
+            _runtime: PhantomData,
+        }
+    }
+}
+
+impl<'r> Future for Delay<'r> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        critical_section::with(|cs| {
+            let this = &unsafe { self.get_unchecked_mut() }.core;
+
+            if this.state.get() == State::Fired {
+                return Poll::Ready(());
+            }
+
+            if this.state.get() == State::Init {
+                let runtime = unsafe { this.runtime.as_ref() };
+
+                if runtime
+                    .clock()
+                    .is_some_and(|clock| clock.now() >= this.deadline)
+                {
+                    return Poll::Ready(());
+                }
+            }
+
+            this.waker.set(Some(cx.waker().clone()));
+            this.insert_back(cs);
+            this.state.set(State::Waiting);
+            Poll::Pending
+        })
+    }
+}
+
+impl<'r> Drop for Delay<'r> {
+    fn drop(&mut self) {
+        critical_section::with(|cs| self.core.remove(cs));
+    }
+}
+
+/// The error returned by [`timeout`] when `future` did not complete in time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elapsed;
+
+/// The [`Future`] returned by [`timeout`].
+#[pin_project::pin_project]
+pub struct Timeout<'r, F> {
+    #[pin]
+    future: F,
+    delay: Delay<'r>,
+}
+
+impl<'r, F: Future> Future for Timeout<'r, F> {
+    type Output = Result<F::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(output) = this.future.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        match Pin::new(this.delay).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(Elapsed)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Runs `future` to completion, or returns `Err(`[`Elapsed`]`)` if it does not complete within
+/// `duration` ticks of `runtime`'s registered [`Clock`].
+///
+/// # Panics
+///
+/// Panics if no [`Clock`] has been registered with `runtime` via
+/// [`Runtime::set_clock`](crate::Runtime::set_clock).
+#[must_use = "Timeout does nothing until it is polled or awaited"]
+pub fn timeout<'r, F: Future>(runtime: &'r Runtime, duration: u64, future: F) -> Timeout<'r, F> {
+    Timeout {
+        future,
+        delay: Delay::new(runtime, duration),
+    }
+}
+
+impl Runtime {
+    /// Wakes every timer whose deadline has passed, even if the clock has jumped past several
+    /// deadlines since the last check.
+    pub(crate) fn fire_due_timers(&self, cs: &CriticalSection) {
+        let Some(clock) = self.clock() else {
+            return;
+        };
+
+        let now = clock.now();
+
+        let mut cursor = self.timers.cursor_front(cs);
+
+        while let Some(timer) = cursor.current() {
+            if timer.deadline() <= now {
+                timer.state.set(State::Fired);
+
+                if let Some(waker) = timer.waker.take() {
+                    waker.wake();
+                }
+
+                cursor.remove_current(cs);
+            } else {
+                cursor.move_next(cs);
+            }
+        }
+    }
+
+    /// The deadline of the next timer to fire, if any timer is currently waiting.
+    pub(crate) fn earliest_timer_deadline(&self, cs: &CriticalSection) -> Option<u64> {
+        let mut earliest = None;
+
+        self.timers.for_each(cs, |timer| {
+            earliest = Some(match earliest {
+                Some(current) if current <= timer.deadline() => current,
+                _ => timer.deadline(),
+            });
+        });
+
+        earliest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{timeout, Clock, Delay, Elapsed};
+    use crate::{Runtime, Task};
+
+    struct TestClock(core::sync::atomic::AtomicU64);
+
+    impl Clock for TestClock {
+        fn now(&self) -> u64 {
+            self.0.load(core::sync::atomic::Ordering::Relaxed)
+        }
+    }
+
+    #[test]
+    fn delay_fires_once_clock_reaches_deadline() {
+        static CLOCK: TestClock = TestClock(core::sync::atomic::AtomicU64::new(0));
+        static COMPLETED: core::sync::atomic::AtomicBool =
+            core::sync::atomic::AtomicBool::new(false);
+
+        let runtime = Runtime::new();
+        runtime.set_clock(&CLOCK);
+
+        let task = core::pin::pin!(Task::new(async {
+            Delay::new(&runtime, 10).await;
+            COMPLETED.store(true, core::sync::atomic::Ordering::Relaxed);
+        }));
+
+        let handle = runtime.spawn(task);
+
+        runtime.run_once();
+        assert!(!COMPLETED.load(core::sync::atomic::Ordering::Relaxed));
+
+        CLOCK.0.store(10, core::sync::atomic::Ordering::Relaxed);
+        runtime.run_once();
+        assert!(COMPLETED.load(core::sync::atomic::Ordering::Relaxed));
+
+        drop(handle);
+    }
+
+    #[test]
+    fn timeout_yields_the_future_output_when_it_completes_first() {
+        static CLOCK: TestClock = TestClock(core::sync::atomic::AtomicU64::new(0));
+
+        let runtime = Runtime::new();
+        runtime.set_clock(&CLOCK);
+
+        assert_eq!(
+            runtime.block_on(timeout(&runtime, 10, async { 42 })),
+            Ok(42)
+        );
+    }
+
+    #[test]
+    fn timeout_yields_elapsed_when_the_deadline_passes_first() {
+        static CLOCK: TestClock = TestClock(core::sync::atomic::AtomicU64::new(0));
+
+        let runtime = Runtime::new();
+        runtime.set_clock(&CLOCK);
+
+        let task = core::pin::pin!(Task::new(timeout(
+            &runtime,
+            10,
+            core::future::pending::<()>()
+        )));
+
+        let handle = runtime.spawn(task);
+
+        runtime.run_once();
+
+        CLOCK.0.store(10, core::sync::atomic::Ordering::Relaxed);
+
+        assert_eq!(handle.join(), Err(Elapsed));
+    }
+}